@@ -1,6 +1,7 @@
 use crate::{ffi, system::Time};
 
 #[derive(Default, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Structure defining a time range
 pub struct TimeSpan {
     /// The beginning offset of the time range.
@@ -22,4 +23,45 @@ impl TimeSpan {
             length: self.length.as_microseconds(),
         }
     }
+
+    /// Returns the end of this time range, i.e. `offset + length`.
+    #[must_use]
+    pub fn end(&self) -> Time {
+        self.offset.saturating_add(self.length)
+    }
+
+    /// Returns `true` if `t` falls within this time range (`offset <= t < end()`).
+    #[must_use]
+    pub fn contains(&self, t: Time) -> bool {
+        t.as_microseconds() >= self.offset.as_microseconds()
+            && t.as_microseconds() < self.end().as_microseconds()
+    }
+
+    /// Returns `true` if this time range and `other` share any point in time.
+    #[must_use]
+    pub fn overlaps(&self, other: &TimeSpan) -> bool {
+        self.length.as_microseconds() > 0
+            && other.length.as_microseconds() > 0
+            && self.offset.as_microseconds() < other.end().as_microseconds()
+            && other.offset.as_microseconds() < self.end().as_microseconds()
+    }
+
+    /// Returns the time range common to both `self` and `other`, or `None` if they don't
+    /// overlap.
+    #[must_use]
+    pub fn intersection(&self, other: &TimeSpan) -> Option<TimeSpan> {
+        if !self.overlaps(other) {
+            return None;
+        }
+        let offset = Time::microseconds(
+            self.offset.as_microseconds().max(other.offset.as_microseconds()),
+        );
+        let end = Time::microseconds(
+            self.end().as_microseconds().min(other.end().as_microseconds()),
+        );
+        Some(TimeSpan {
+            offset,
+            length: end.saturating_sub(offset),
+        })
+    }
 }
@@ -4,7 +4,7 @@ use std::{
     io::{Read, Seek, SeekFrom},
     marker::PhantomData,
     os::raw::{c_longlong, c_void},
-    ptr,
+    slice,
 };
 
 #[allow(clippy::comparison_chain)]
@@ -17,13 +17,17 @@ unsafe extern "C" fn read<T: Read + Seek>(
     if size == 0 {
         return 0;
     } else if size > 0 {
-        let mut chunk = stream.take(size.try_into().unwrap());
-        let mut buf = vec![];
-        let result = chunk.read_to_end(&mut buf);
-        if let Ok(bytes_read) = result {
-            ptr::copy_nonoverlapping(buf.as_ptr(), data as *mut u8, bytes_read);
-            return bytes_read as _;
+        let buf = slice::from_raw_parts_mut(data as *mut u8, size.try_into().unwrap());
+        let mut total_read = 0;
+        while total_read < buf.len() {
+            match stream.read(&mut buf[total_read..]) {
+                Ok(0) => break,
+                Ok(bytes_read) => total_read += bytes_read,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(_) => return -1,
+            }
         }
+        return total_read as _;
     }
     -1
 }
@@ -1,6 +1,9 @@
+use std::convert::TryFrom;
+use std::fmt;
 use std::ops::{
     Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
 };
+use std::time::Duration;
 
 pub use crate::ffi::system::sfTime as Time;
 
@@ -45,6 +48,139 @@ impl Time {
 
     /// Predefined "zero" time value.
     pub const ZERO: Time = Time{microseconds: 0};
+
+    /// Splits this time value into hours, minutes, seconds, and a sub-second remainder.
+    ///
+    /// The returned components describe the *magnitude* of the time value; a negative
+    /// `Time` yields the same components as its positive counterpart (see the [`Display`]
+    /// impl for how the sign is surfaced). `nanos_equiv` is the sub-second remainder
+    /// expressed in nanoseconds, even though `Time`'s actual resolution is microseconds.
+    ///
+    /// [`Display`]: std::fmt::Display
+    #[must_use]
+    pub fn to_hms(self) -> (i64, u8, u8, u32) {
+        let micros = self.microseconds.unsigned_abs();
+        let total_seconds = micros / 1_000_000;
+        let sub_micros = (micros % 1_000_000) as u32;
+        let hours = (total_seconds / 3600) as i64;
+        let minutes = ((total_seconds % 3600) / 60) as u8;
+        let seconds = (total_seconds % 60) as u8;
+        (hours, minutes, seconds, sub_micros * 1000)
+    }
+
+    /// Converts this time value into a [`Duration`].
+    ///
+    /// Returns `None` if the time value is negative, since [`Duration`] has no
+    /// representation for negative spans.
+    #[must_use]
+    pub fn to_std_duration(self) -> Option<Duration> {
+        u64::try_from(self.microseconds)
+            .ok()
+            .map(Duration::from_micros)
+    }
+
+    /// Converts a [`Duration`] into a time value.
+    ///
+    /// Saturates at `Time::microseconds(i64::MAX)` if the duration doesn't fit.
+    #[must_use]
+    pub fn from_std_duration(duration: Duration) -> Self {
+        let micros = u64::try_from(duration.as_micros()).unwrap_or(u64::MAX);
+        Time::microseconds(i64::try_from(micros).unwrap_or(i64::MAX))
+    }
+
+    /// Computes `self + rhs`, returning `None` if the result would overflow.
+    #[must_use]
+    pub fn checked_add(self, rhs: Time) -> Option<Time> {
+        self.microseconds
+            .checked_add(rhs.microseconds)
+            .map(Time::microseconds)
+    }
+
+    /// Computes `self - rhs`, returning `None` if the result would overflow.
+    #[must_use]
+    pub fn checked_sub(self, rhs: Time) -> Option<Time> {
+        self.microseconds
+            .checked_sub(rhs.microseconds)
+            .map(Time::microseconds)
+    }
+
+    /// Computes `self * rhs`, returning `None` if the result would overflow.
+    #[must_use]
+    pub fn checked_mul(self, rhs: i64) -> Option<Time> {
+        self.microseconds.checked_mul(rhs).map(Time::microseconds)
+    }
+
+    /// Computes `self / rhs`, returning `None` if `rhs` is zero or the result would overflow.
+    #[must_use]
+    pub fn checked_div(self, rhs: i64) -> Option<Time> {
+        self.microseconds.checked_div(rhs).map(Time::microseconds)
+    }
+
+    /// Computes `self + rhs`, saturating at `Time::microseconds(i64::MAX)` or
+    /// `Time::microseconds(i64::MIN)` on overflow.
+    #[must_use]
+    pub fn saturating_add(self, rhs: Time) -> Time {
+        Time::microseconds(self.microseconds.saturating_add(rhs.microseconds))
+    }
+
+    /// Computes `self - rhs`, saturating at `Time::microseconds(i64::MAX)` or
+    /// `Time::microseconds(i64::MIN)` on overflow.
+    #[must_use]
+    pub fn saturating_sub(self, rhs: Time) -> Time {
+        Time::microseconds(self.microseconds.saturating_sub(rhs.microseconds))
+    }
+
+    /// Computes `self * rhs`, saturating at `Time::microseconds(i64::MAX)` or
+    /// `Time::microseconds(i64::MIN)` on overflow.
+    #[must_use]
+    pub fn saturating_mul(self, rhs: i64) -> Time {
+        Time::microseconds(self.microseconds.saturating_mul(rhs))
+    }
+}
+
+/// The error returned when converting a negative [`Time`] into a [`Duration`] fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryFromTimeError(());
+
+impl fmt::Display for TryFromTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "out of range conversion from a negative Time to a Duration")
+    }
+}
+
+impl std::error::Error for TryFromTimeError {}
+
+impl TryFrom<Time> for Duration {
+    type Error = TryFromTimeError;
+
+    fn try_from(time: Time) -> Result<Self, Self::Error> {
+        time.to_std_duration().ok_or(TryFromTimeError(()))
+    }
+}
+
+impl From<Duration> for Time {
+    fn from(duration: Duration) -> Self {
+        Time::from_std_duration(duration)
+    }
+}
+
+impl fmt::Display for Time {
+    /// Formats as `H:MM:SS.fffffffff`, truncated to `f.precision()` fractional digits
+    /// (6 by default, since that's `Time`'s underlying resolution). Negative values are
+    /// prefixed with a `-`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (hours, minutes, seconds, nanos_equiv) = self.to_hms();
+        if self.microseconds < 0 {
+            write!(f, "-")?;
+        }
+        write!(f, "{hours}:{minutes:02}:{seconds:02}")?;
+        let precision = f.precision().unwrap_or(6).min(9);
+        if precision > 0 {
+            let nanos = format!("{nanos_equiv:09}");
+            write!(f, ".{}", &nanos[..precision])?;
+        }
+        Ok(())
+    }
 }
 
 impl Neg for Time {
@@ -192,3 +328,19 @@ impl Default for Time {
         Self::ZERO
     }
 }
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Time {
+    /// Serializes as the underlying microsecond count, for a stable, lossless,
+    /// human-inspectable representation.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.microseconds)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Time {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        i64::deserialize(deserializer).map(Time::microseconds)
+    }
+}